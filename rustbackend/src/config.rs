@@ -0,0 +1,45 @@
+use crate::error::Error;
+
+/// Runtime configuration, loaded once from the environment at startup.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub database_url: String,
+    pub bind_addr: String,
+    pub jwt_secret: String,
+    pub jwt_expires_in: String,
+    pub jwt_maxage: i64,
+    pub log_filter: String,
+    pub static_dir: String,
+}
+
+impl Config {
+    /// Reads each setting from the environment, falling back to a sensible
+    /// default where one exists. Returns an error if a required value is
+    /// missing or can't be parsed into its expected type.
+    pub fn init() -> Result<Self, Error> {
+        let database_url = env_or("DATABASE_URL", "postgres://localhost/rustbackend");
+        let bind_addr = env_or("BIND_ADDR", "127.0.0.1:8000");
+        let jwt_secret = std::env::var("JWT_SECRET")
+            .map_err(|_| Error::Internal("JWT_SECRET must be set".into()))?;
+        let jwt_expires_in = env_or("JWT_EXPIRES_IN", "60m");
+        let jwt_maxage = env_or("JWT_MAXAGE", "60")
+            .parse()
+            .map_err(|_| Error::Internal("JWT_MAXAGE must be an integer".into()))?;
+        let log_filter = env_or("RUST_LOG", "info");
+        let static_dir = env_or("STATIC_DIR", "static");
+
+        Ok(Self {
+            database_url,
+            bind_addr,
+            jwt_secret,
+            jwt_expires_in,
+            jwt_maxage,
+            log_filter,
+            static_dir,
+        })
+    }
+}
+
+fn env_or(key: &str, default: &str) -> String {
+    std::env::var(key).unwrap_or_else(|_| default.to_string())
+}
@@ -0,0 +1,48 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+
+/// The crate-wide error type. Every handler that can fail should return
+/// `crate::error::Result<T>` so axum can turn failures into a consistent
+/// JSON response via `IntoResponse`.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("io error: {0}")]
+    IO(#[from] std::io::Error),
+
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    #[error("internal error: {0}")]
+    Internal(String),
+
+    #[error("bad request: {0}")]
+    BadRequest(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Error::IO(_) | Error::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::NotFound(_) => StatusCode::NOT_FOUND,
+            Error::BadRequest(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+        let body = Json(json!({
+            "status": "error",
+            "message": self.to_string(),
+        }));
+
+        (status, body).into_response()
+    }
+}
@@ -0,0 +1,56 @@
+use std::{convert::Infallible, sync::Arc, time::Duration};
+
+use axum::{
+    extract::State,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use serde::Serialize;
+use tokio::sync::broadcast;
+use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
+
+use crate::AppState;
+
+/// A message pushed to every subscriber of the `/api/events` stream.
+#[derive(Debug, Clone, Serialize)]
+pub struct AppEvent {
+    pub kind: String,
+    pub payload: serde_json::Value,
+}
+
+/// Creates the broadcast channel backing the `/api/events` endpoint. Stored in
+/// `AppState` so any part of the app can publish onto it.
+pub fn channel() -> broadcast::Sender<AppEvent> {
+    let (tx, _rx) = broadcast::channel(100);
+    tx
+}
+
+/// Publishes an event to every currently-connected `/api/events` client.
+/// Silently drops the event if nobody is listening.
+pub fn publish(
+    sender: &broadcast::Sender<AppEvent>,
+    kind: impl Into<String>,
+    payload: serde_json::Value,
+) {
+    let _ = sender.send(AppEvent {
+        kind: kind.into(),
+        payload,
+    });
+}
+
+pub async fn sse_handler(
+    State(state): State<Arc<AppState>>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let rx = state.events.subscribe();
+    let stream = BroadcastStream::new(rx).filter_map(|msg| async move {
+        match msg {
+            Ok(event) => Some(Ok(Event::default().json_data(&event).unwrap())),
+            Err(_lagged) => None,
+        }
+    });
+
+    Sse::new(stream).keep_alive(
+        KeepAlive::new()
+            .interval(Duration::from_secs(15))
+            .text("keep-alive"),
+    )
+}
@@ -1,18 +1,106 @@
-use axum::{routing::get, Router};
-use std::sync::Arc;
-use tower_http::trace::TraceLayer;
+use axum::{
+    error_handling::HandleErrorLayer,
+    extract::{ConnectInfo, MatchedPath},
+    http::Request,
+    routing::get,
+    Json, Router,
+};
+use axum_extra::routing::RouterExt;
+use serde_json::json;
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+use tokio::sync::broadcast;
+use tower::ServiceBuilder;
+use tower_http::{
+    services::{ServeDir, ServeFile},
+    trace::TraceLayer,
+};
+use tracing::Span;
+
+pub mod config;
+pub mod error;
+pub mod events;
+pub mod routes;
+pub mod ws;
+
+use config::Config;
+use error::Error;
+use events::AppEvent;
+use routes::HealthCheck;
+use ws::WsState;
 
 pub struct AppState {
-    // For future use when we add database connections, configuration, etc.
+    pub config: Arc<Config>,
+    pub events: broadcast::Sender<AppEvent>,
+    pub ws: WsState,
 }
 
 pub fn create_router(state: Arc<AppState>) -> Router {
+    let static_dir = &state.config.static_dir;
+    let serve_dir = ServeDir::new(static_dir)
+        .fallback(ServeFile::new(format!("{static_dir}/index.html")));
+    let static_service = ServiceBuilder::new()
+        .layer(HandleErrorLayer::new(handle_static_error))
+        .service(serve_dir);
+
+    let api_routes = Router::new()
+        .typed_get(health_check)
+        .route("/events", get(events::sse_handler))
+        .route("/ws", get(ws::ws_handler))
+        .fallback(fallback);
+
     Router::new()
-        .route("/", get(health_check))
-        .layer(TraceLayer::new_for_http())
+        .nest("/api", api_routes)
+        .fallback_service(static_service)
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(|request: &Request<_>| {
+                    let client_ip = request
+                        .extensions()
+                        .get::<ConnectInfo<SocketAddr>>()
+                        .map(|ConnectInfo(addr)| addr.to_string())
+                        .unwrap_or_else(|| "unknown".into());
+                    let path = request
+                        .extensions()
+                        .get::<MatchedPath>()
+                        .map(MatchedPath::as_str)
+                        .unwrap_or_else(|| request.uri().path());
+
+                    tracing::info_span!(
+                        "request",
+                        client_ip = %client_ip,
+                        method = %request.method(),
+                        path = %path,
+                    )
+                })
+                .on_response(|response: &axum::http::Response<_>, latency: Duration, span: &Span| {
+                    span.in_scope(|| {
+                        tracing::info!(
+                            status = %response.status(),
+                            latency_ms = %latency.as_millis(),
+                            "access"
+                        );
+                    });
+                }),
+        )
         .with_state(state)
 }
 
-async fn health_check() -> &'static str {
-    "Hello, World!"
+/// Maps IO failures from the static file service onto the crate's JSON
+/// error response instead of axum's default plaintext 500.
+async fn handle_static_error(err: std::io::Error) -> Error {
+    Error::IO(err)
+}
+
+async fn health_check(_: HealthCheck) -> Json<serde_json::Value> {
+    Json(json!({
+        "status": "success",
+        "message": "Service is healthy",
+    }))
+}
+
+/// Catches any `/api/*` request that doesn't match a route and returns the
+/// crate's JSON error response instead of axum's default plaintext 404.
+/// Unmatched paths outside `/api` fall through to the static/SPA service.
+async fn fallback() -> Error {
+    Error::NotFound("no route matches this path".into())
 }
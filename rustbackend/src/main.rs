@@ -1,43 +1,68 @@
-use axum::{routing::get, Router};
 use std::{net::SocketAddr, sync::Arc};
-use tower_http::trace::TraceLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-pub struct AppState {
-    // For future use when we add database connections, configuration, etc.
-}
+use rustbackend::{config::Config, create_router, events, ws::WsState, AppState};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Load configuration
+    let config = Arc::new(Config::init()?);
+
     // Initialize tracing
     tracing_subscriber::registry()
-        .with(tracing_subscriber::EnvFilter::new(
-            std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
-        ))
+        .with(tracing_subscriber::EnvFilter::new(config.log_filter.clone()))
         .with(tracing_subscriber::fmt::layer())
         .init();
 
     // Build app state
-    let state = Arc::new(AppState {});
+    let addr: SocketAddr = config.bind_addr.parse()?;
+    let state = Arc::new(AppState {
+        config,
+        events: events::channel(),
+        ws: WsState::new(),
+    });
 
     // Build router
     let app = create_router(state);
 
     // Run server
-    let addr = SocketAddr::from(([127, 0, 0, 1], 8000));
     tracing::info!("listening on {}", addr);
-    axum::serve(tokio::net::TcpListener::bind(addr).await?, app).await?;
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(shutdown_signal())
+    .await?;
 
     Ok(())
 }
 
-pub fn create_router(state: Arc<AppState>) -> Router {
-    Router::new()
-        .route("/", get(health_check))
-        .layer(TraceLayer::new_for_http())
-        .with_state(state)
-}
+/// Resolves once either `SIGINT` (Ctrl-C) or `SIGTERM` is received, so
+/// `main` can hand it to `with_graceful_shutdown` and let in-flight
+/// requests finish before the process exits.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl-C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
 
-async fn health_check() -> &'static str {
-    "Hello, World!"
+    tracing::info!("shutdown signal received, shutting down gracefully");
 }
@@ -0,0 +1,8 @@
+use axum_extra::routing::TypedPath;
+use serde::Deserialize;
+
+/// `GET /api/healthcheck` — liveness probe. Nested under `/api` by
+/// `create_router`, so the path here is relative to that prefix.
+#[derive(TypedPath, Deserialize)]
+#[typed_path("/healthcheck")]
+pub struct HealthCheck;
@@ -0,0 +1,109 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::Response,
+};
+use futures_util::{SinkExt, StreamExt};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::AppState;
+
+/// Shared state for the `/api/ws` chat/fanout endpoint: a broadcast channel
+/// every connected socket subscribes to, and the set of client names
+/// currently connected (used to reject duplicate names and announce
+/// joins/leaves).
+pub struct WsState {
+    pub tx: broadcast::Sender<String>,
+    pub clients: Mutex<HashSet<String>>,
+}
+
+impl WsState {
+    pub fn new() -> Self {
+        let (tx, _rx) = broadcast::channel(100);
+        Self {
+            tx,
+            clients: Mutex::new(HashSet::new()),
+        }
+    }
+}
+
+impl Default for WsState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub async fn ws_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<Arc<AppState>>,
+) -> Response {
+    ws.on_upgrade(|socket| handle_socket(socket, state))
+}
+
+async fn handle_socket(socket: WebSocket, state: Arc<AppState>) {
+    let (mut sender, mut receiver) = socket.split();
+
+    // The first message a client sends is treated as its display name.
+    let name = match receiver.next().await {
+        Some(Ok(Message::Text(name))) => name,
+        _ => return,
+    };
+
+    {
+        let mut clients = state.ws.clients.lock().unwrap();
+        if !clients.insert(name.clone()) {
+            // Name already taken; drop the connection.
+            return;
+        }
+    }
+
+    let _ = state.ws.tx.send(format!("{name} joined"));
+    let mut rx = state.ws.tx.subscribe();
+
+    let name_for_send = name.clone();
+    let mut send_task = tokio::spawn(async move {
+        // Chat messages from this same connection are broadcast back to
+        // its own receiver too; skip them so a client never sees its own
+        // message echoed back.
+        let self_prefix = format!("{name_for_send}: ");
+        loop {
+            match rx.recv().await {
+                Ok(msg) => {
+                    if msg.starts_with(&self_prefix) {
+                        continue;
+                    }
+                    if sender.send(Message::Text(msg)).await.is_err() {
+                        break;
+                    }
+                }
+                // The client fell behind the broadcast channel's buffer;
+                // skip the messages it missed instead of disconnecting it.
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+
+    let tx = state.ws.tx.clone();
+    let name_for_recv = name.clone();
+    let mut recv_task = tokio::spawn(async move {
+        while let Some(Ok(Message::Text(text))) = receiver.next().await {
+            let _ = tx.send(format!("{name_for_recv}: {text}"));
+        }
+    });
+
+    tokio::select! {
+        _ = &mut send_task => recv_task.abort(),
+        _ = &mut recv_task => send_task.abort(),
+    }
+
+    state.ws.clients.lock().unwrap().remove(&name);
+    let _ = state.ws.tx.send(format!("{name} left"));
+}
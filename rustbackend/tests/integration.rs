@@ -1,20 +1,35 @@
+use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
+
+use rustbackend::{config::Config, create_router, events, events::AppEvent, ws::WsState, AppState};
 use tokio::net::TcpListener;
-use tokio::sync::oneshot;
+use tokio::sync::{broadcast, oneshot};
+use tokio::task::JoinHandle;
 
-#[tokio::test]
-async fn test_health_endpoint() {
-    // Start the server in a background task
+struct TestServer {
+    addr: SocketAddr,
+    events_tx: broadcast::Sender<AppEvent>,
+    shutdown_tx: oneshot::Sender<()>,
+    server_handle: JoinHandle<()>,
+}
+
+async fn spawn_test_server() -> TestServer {
     let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
     let addr = listener.local_addr().unwrap();
 
-    // Use rustbackend library
-    use rustbackend::{create_router, AppState};
-    let app_state = Arc::new(AppState {});
+    std::env::set_var("JWT_SECRET", "test-secret");
+    std::env::set_var("STATIC_DIR", "tests/fixtures/static");
+    let config = Arc::new(Config::init().unwrap());
+    let events_tx = events::channel();
+    let app_state = Arc::new(AppState {
+        config,
+        events: events_tx.clone(),
+        ws: WsState::new(),
+    });
     let app = create_router(app_state);
 
-    // Spawn the server task with graceful shutdown
     let server_handle = tokio::spawn(async move {
         axum::serve(listener, app)
             .with_graceful_shutdown(async {
@@ -24,20 +39,144 @@ async fn test_health_endpoint() {
             .expect("Server error");
     });
 
-    // Create the HTTP client
+    TestServer {
+        addr,
+        events_tx,
+        shutdown_tx,
+        server_handle,
+    }
+}
+
+impl TestServer {
+    async fn shutdown(self) {
+        let _ = self.shutdown_tx.send(());
+        let _ = self.server_handle.await;
+    }
+}
+
+#[tokio::test]
+async fn test_health_endpoint() {
+    let server = spawn_test_server().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("http://{}/api/healthcheck", server.addr))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["status"], "success");
+
+    server.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_spa_fallback_serves_index_html() {
+    let server = spawn_test_server().await;
     let client = reqwest::Client::new();
 
-    // Test the health endpoint
+    // A client-side route that doesn't match any API endpoint should still
+    // get the SPA shell, not a JSON 404.
     let response = client
-        .get(format!("http://{}", addr))
+        .get(format!("http://{}/dashboard", server.addr))
         .send()
         .await
         .expect("Failed to send request");
 
     assert_eq!(response.status(), 200);
-    assert_eq!(response.text().await.unwrap(), "Hello, World!");
+    let body = response.text().await.unwrap();
+    assert!(body.contains("SPA shell"));
+
+    server.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_api_fallback_returns_json_404() {
+    let server = spawn_test_server().await;
+    let client = reqwest::Client::new();
+
+    // An unmatched path under /api should get the crate's JSON error
+    // response, not the SPA shell or axum's default plaintext 404.
+    let response = client
+        .get(format!("http://{}/api/does-not-exist", server.addr))
+        .send()
+        .await
+        .expect("Failed to send request");
+
+    assert_eq!(response.status(), 404);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["status"], "error");
+
+    server.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_sse_endpoint_streams_published_events() {
+    use futures_util::StreamExt;
+
+    let server = spawn_test_server().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .get(format!("http://{}/api/events", server.addr))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), 200);
+
+    let mut stream = response.bytes_stream();
+
+    events::publish(
+        &server.events_tx,
+        "greeting",
+        serde_json::json!({"text": "hello"}),
+    );
+
+    let chunk = tokio::time::timeout(Duration::from_secs(2), stream.next())
+        .await
+        .expect("timed out waiting for SSE event")
+        .expect("stream ended unexpectedly")
+        .unwrap();
+    let text = String::from_utf8(chunk.to_vec()).unwrap();
+
+    assert!(text.contains("greeting"));
+    assert!(text.contains("hello"));
+
+    server.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_ws_fanout_excludes_sender() {
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
+
+    let server = spawn_test_server().await;
+    let ws_url = format!("ws://{}/api/ws", server.addr);
+
+    let (mut alice, _) = connect_async(&ws_url).await.unwrap();
+    alice.send(WsMessage::Text("alice".into())).await.unwrap();
+
+    let (mut bob, _) = connect_async(&ws_url).await.unwrap();
+    bob.send(WsMessage::Text("bob".into())).await.unwrap();
+
+    // Alice, already connected and subscribed, sees Bob join.
+    let joined = alice.next().await.unwrap().unwrap();
+    assert_eq!(joined.into_text().unwrap(), "bob joined");
+
+    alice.send(WsMessage::Text("hello".into())).await.unwrap();
+
+    // Bob receives Alice's message...
+    let received = bob.next().await.unwrap().unwrap();
+    assert_eq!(received.into_text().unwrap(), "alice: hello");
+
+    // ...but Alice does not see her own message echoed back.
+    let self_echo = tokio::time::timeout(Duration::from_millis(300), alice.next()).await;
+    assert!(
+        self_echo.is_err(),
+        "sender should not receive its own chat message"
+    );
 
-    // Shutdown the server
-    let _ = shutdown_tx.send(());
-    let _ = server_handle.await;
+    server.shutdown().await;
 }